@@ -4,10 +4,79 @@ use binary_layout::{define_layout, LayoutAs, FieldSliceAccess};
 
 const PAGE_SIZE: usize = 4096; // 4Kb
 
-#[derive(Debug, PartialEq)]
+/// Encodes `v` as a SQLite-style big-endian varint into `buf`, returning the
+/// number of bytes written (1 to 9). The first 8 bytes each carry 7 payload
+/// bits with the high bit set as a continuation flag; if all 56 of those bits
+/// are still not enough, a 9th byte carries the remaining 8 bits outright.
+pub fn write_varint(buf: &mut [u8], v: u64) -> usize {
+    if v & 0xff00_0000_0000_0000 != 0 {
+        buf[8] = v as u8;
+        let mut rest = v >> 8;
+        for i in (0..8).rev() {
+            buf[i] = ((rest & 0x7f) as u8) | 0x80;
+            rest >>= 7;
+        }
+        return 9;
+    }
+
+    let mut groups = [0u8; 9];
+    let mut n = 0usize;
+    let mut rest = v;
+    loop {
+        groups[n] = ((rest & 0x7f) as u8) | 0x80;
+        rest >>= 7;
+        n += 1;
+        if rest == 0 {
+            break;
+        }
+    }
+    groups[0] &= 0x7f; // the least-significant group is written last and ends the varint
+    for i in 0..n {
+        buf[i] = groups[n - 1 - i];
+    }
+    n
+}
+
+/// Decodes a varint written by `write_varint`, returning `(value, bytes_consumed)`.
+/// Returns `None` rather than panicking if `buf` runs out before a terminating
+/// byte (high bit clear, or the 9th byte) is found.
+pub fn parse_varint(buf: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    for i in 0..8 {
+        let byte = *buf.get(i)?;
+        value = (value << 7) | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    let byte = *buf.get(8)?;
+    value = (value << 8) | byte as u64;
+    Some((value, 9))
+}
+
+/// 128-bit non-cryptographic checksum (two independently-seeded FNV-1a
+/// passes) used to detect torn writes and bit-rot when a page is read back
+/// from disk. Stands in for redb's XXH3-based leaf/branch checksums without
+/// pulling in an external hashing dependency.
+fn checksum_page(data: &[u8]) -> u128 {
+    fn fnv1a64(data: &[u8], seed: u64) -> u64 {
+        let mut hash = seed;
+        for &b in data {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        hash
+    }
+
+    let lo = fnv1a64(data, 0xcbf29ce484222325);
+    let hi = fnv1a64(data, 0x84222325cbf29ce4);
+    ((hi as u128) << 64) | lo as u128
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct PageId(NonZeroU64);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct MaybePageId(u64);
 
 impl LayoutAs<u64> for MaybePageId {
@@ -30,7 +99,7 @@ impl MaybePageId {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PageType {
     KeyPage = 0,
     KeyValuePage = 1,
@@ -42,6 +111,7 @@ define_layout!(page_header, LittleEndian, {
     upper_offset: u16,
     overflow_page: MaybePageId as u64,
     flags: u16,
+    checksum: u128,
 });
 const HEADER_SIZE: usize = binary_layout::internal::unwrap_field_size(page_header::SIZE);
 
@@ -62,10 +132,23 @@ define_layout!(page, LittleEndian, {
 /// 4 bytes, 0 means None
 /// PAGE_FLAG
 /// 2 bytes
+/// CHECKSUM
+/// 16 bytes, covers the whole page with the checksum field itself zeroed out
 pub struct Page {
     data: [u8; PAGE_SIZE],
 }
 
+/// Decoded form of a 4-byte slot in the pointer array: where the cell's bytes
+/// live in the body, how long they are, and the flags packed into the top
+/// bits of the length field.
+#[derive(Debug, Clone, Copy)]
+struct CellPointer {
+    addr: u16,
+    len: u16,
+    spilled: bool,
+    tombstone: bool,
+}
+
 impl Page {
     fn header_mut_view(&mut self) -> page_header::View<impl AsRef<[u8]> + AsMut<[u8]> + '_> {
         let page_view = page::View::new(&mut self.data[..]);
@@ -95,12 +178,30 @@ impl Page {
         };
         s.header_mut_view().magic_mut().copy_from_slice(b"PAGE");
         s.header_mut_view().lower_offset_mut().write(0);
-        s.header_mut_view().upper_offset_mut().write((PAGE_SIZE-HEADER_SIZE) as u16);
+        s.header_mut_view().upper_offset_mut().write((PAGE_SIZE-HEADER_SIZE-Self::SUMMARY_REGION_SIZE) as u16);
         s.header_mut_view().overflow_page_mut().write(MaybePageId::from_page_id(None));
         s.header_mut_view().flags_mut().write(0);
+        s.seal();
         s
     }
 
+    /// Recomputes the checksum over the whole page and stores it in the header.
+    /// Must be called before a page is flushed to disk.
+    fn seal(&mut self) {
+        self.header_mut_view().checksum_mut().write(0);
+        let checksum = checksum_page(&self.data);
+        self.header_mut_view().checksum_mut().write(checksum);
+    }
+
+    /// Validates the stored checksum against the page's current contents.
+    /// Returns `false` if the page was torn or corrupted on disk.
+    pub fn verify(&self) -> bool {
+        let stored = self.header_view().checksum().read();
+        let mut scratch = Page { data: self.data };
+        scratch.header_mut_view().checksum_mut().write(0);
+        stored == checksum_page(&scratch.data)
+    }
+
     fn write_cell_data(&mut self, from_offset: u16, data: &[u8]) {
         let idx = from_offset as usize;
         let idx_to = idx + data.len();
@@ -113,27 +214,70 @@ impl Page {
         &self.body()[idx..idx_to]
     }
 
-    // 1 pointer is 4 bytes
-    // 2 for offset and 2 for len
-    fn write_pointer(&mut self, from_offset: u16, addr: u16, len: u16) {
+    // 1 pointer is 4 bytes: 2 for offset and 2 for len, with the top two bits
+    // of len repurposed as flags (spilled, tombstone) since a cell can never
+    // be anywhere close to 0x3fff bytes long on a 4Kb page.
+    const SPILLED_BIT: u16 = 0x8000;
+    const TOMBSTONE_BIT: u16 = 0x4000;
+    const LEN_MASK: u16 = !(Self::SPILLED_BIT | Self::TOMBSTONE_BIT);
+
+    fn write_pointer(&mut self, from_offset: u16, ptr: CellPointer) {
         let idx = from_offset as usize;
-        self.body_mut()[idx..idx + 2].copy_from_slice(&addr.to_le_bytes());
-        self.body_mut()[idx + 2..idx + 4].copy_from_slice(&len.to_le_bytes());
+        self.body_mut()[idx..idx + 2].copy_from_slice(&ptr.addr.to_le_bytes());
+        let mut len_field = ptr.len;
+        if ptr.spilled {
+            len_field |= Self::SPILLED_BIT;
+        }
+        if ptr.tombstone {
+            len_field |= Self::TOMBSTONE_BIT;
+        }
+        self.body_mut()[idx + 2..idx + 4].copy_from_slice(&len_field.to_le_bytes());
     }
 
-    fn read_pointer(&self, from_offset: u16) -> (u16, u16) {
+    fn read_pointer(&self, from_offset: u16) -> CellPointer {
         // if read from non-ptr range
         if from_offset > self.header_view().lower_offset().read() {
             panic!("Not a pointer");
         }
         let idx = from_offset as usize;
         let addr = u16::from_le_bytes(self.body()[idx..idx+2].try_into().unwrap());
-        let len = u16::from_le_bytes(self.body()[idx+2..idx+4].try_into().unwrap());
+        let len_field = u16::from_le_bytes(self.body()[idx+2..idx+4].try_into().unwrap());
 
-        (addr, len)
+        CellPointer {
+            addr,
+            len: len_field & Self::LEN_MASK,
+            spilled: len_field & Self::SPILLED_BIT != 0,
+            tombstone: len_field & Self::TOMBSTONE_BIT != 0,
+        }
     }
 
-    fn add_cell(&mut self, data: &[u8]) {
+    /// Scans the pointer array for a tombstoned slot with at least `needed`
+    /// bytes of freed space, preferring the smallest such hole (best fit).
+    fn find_free_hole(&self, needed: usize) -> Option<(u16, CellPointer)> {
+        let mut best: Option<(u16, CellPointer)> = None;
+        for slot in 0..self.cells_count() {
+            let at = slot * 4;
+            let ptr = self.read_pointer(at);
+            if ptr.tombstone && ptr.len as usize >= needed {
+                let better = match best {
+                    Some((_, best_ptr)) => ptr.len < best_ptr.len,
+                    None => true,
+                };
+                if better {
+                    best = Some((at, ptr));
+                }
+            }
+        }
+        best
+    }
+
+    fn add_cell_raw(&mut self, data: &[u8], spilled: bool) {
+        if let Some((at, hole)) = self.find_free_hole(data.len()) {
+            self.write_cell_data(hole.addr, data);
+            self.write_pointer(at, CellPointer { addr: hole.addr, len: data.len() as u16, spilled, tombstone: false });
+            return;
+        }
+
         let lower_offset = self.header_view().lower_offset().read();
         let upper_offset = self.header_view().upper_offset().read();
         if data.len() > (upper_offset - lower_offset) as usize {
@@ -141,22 +285,460 @@ impl Page {
         }
         let addr = upper_offset - data.len() as u16;
         self.write_cell_data(addr, data);
-        self.write_pointer(lower_offset, addr as u16, data.len() as u16);
+        self.write_pointer(lower_offset, CellPointer { addr, len: data.len() as u16, spilled, tombstone: false });
         let new_lower = lower_offset + 4 as u16;
-        let new_upper = addr as u16;
+        let new_upper = addr;
         self.header_mut_view().lower_offset_mut().write(new_lower);
         self.header_mut_view().upper_offset_mut().write(new_upper);
     }
 
+    fn add_cell(&mut self, data: &[u8]) {
+        self.add_cell_raw(data, false);
+    }
+
+    /// Tombstones the `nth` cell's pointer slot, returning its space to the
+    /// free list so a later `add_cell` can reuse it. Follows the "holes"
+    /// design: the free list isn't stored separately, it's derived on demand
+    /// by scanning for tombstoned pointers.
+    fn remove_nth_cell(&mut self, nth: usize) {
+        let at = (nth * 4) as u16;
+        let mut ptr = self.read_pointer(at);
+        ptr.tombstone = true;
+        self.write_pointer(at, ptr);
+    }
+
+    /// Total bytes held in tombstoned holes — reclaimable by `compact`.
+    fn free_space(&self) -> usize {
+        (0..self.cells_count())
+            .map(|slot| self.read_pointer(slot * 4))
+            .filter(|ptr| ptr.tombstone)
+            .map(|ptr| ptr.len as usize)
+            .sum()
+    }
+
+    /// Rewrites all live cells contiguously against `upper_offset`, rebuilds
+    /// the pointer array with tombstones dropped, and resets the free list.
+    fn compact(&mut self) {
+        let live: Vec<(Vec<u8>, bool)> = (0..self.cells_count())
+            .map(|slot| self.read_pointer(slot * 4))
+            .filter(|ptr| !ptr.tombstone)
+            .map(|ptr| (self.read_cell(ptr.addr, ptr.len).to_vec(), ptr.spilled))
+            .collect();
+
+        let mut upper_offset = (PAGE_SIZE - HEADER_SIZE - Self::SUMMARY_REGION_SIZE) as u16;
+        let mut lower_offset = 0u16;
+        for (bytes, spilled) in live {
+            upper_offset -= bytes.len() as u16;
+            self.write_cell_data(upper_offset, &bytes);
+            self.write_pointer(lower_offset, CellPointer { addr: upper_offset, len: bytes.len() as u16, spilled, tombstone: false });
+            lower_offset += 4;
+        }
+        self.header_mut_view().lower_offset_mut().write(lower_offset);
+        self.header_mut_view().upper_offset_mut().write(upper_offset);
+    }
+
+    /// Byte size of the header a spilled cell carries in front of its locally
+    /// stored payload: the total payload length plus the id of the first
+    /// overflow page in the chain (0 if the payload fit on this page after all).
+    const SPILL_CELL_HEADER_SIZE: usize = 8 + 8;
+
+    /// Writes `data` into this page, spilling whatever doesn't fit into a chain
+    /// of freshly allocated overflow pages. `alloc_page` mints a `PageId` for
+    /// each page in the chain; the caller is responsible for persisting the
+    /// returned `(PageId, Page)` pairs. Mirrors SQLite/prsqlite's overflow
+    /// pages: the chain is linked through each page's own `overflow_page`
+    /// header field, terminated by `None`.
+    fn add_cell_spilled<F: FnMut() -> PageId>(
+        &mut self,
+        data: &[u8],
+        mut alloc_page: F,
+    ) -> Vec<(PageId, Page)> {
+        let lower_offset = self.header_view().lower_offset().read();
+        let upper_offset = self.header_view().upper_offset().read();
+        let avail = (upper_offset - lower_offset) as usize;
+        // The in-page spill cell always needs room for its header and its
+        // 4-byte pointer slot, however little of the payload ends up stored
+        // locally. Check this before minting any overflow pages below, so a
+        // too-tight page fails here instead of panicking in `add_cell_raw`
+        // after `alloc_page` has already handed out page ids nothing will end
+        // up referencing.
+        if avail < Self::SPILL_CELL_HEADER_SIZE + 4 {
+            panic!("Overflow page");
+        }
+        let local_cap = avail - Self::SPILL_CELL_HEADER_SIZE - 4;
+        let local_len = data.len().min(local_cap);
+        let (local_data, mut rest) = data.split_at(local_len);
+
+        let body_cap = PAGE_SIZE - HEADER_SIZE;
+        let mut chain: Vec<(PageId, Page)> = Vec::new();
+        while !rest.is_empty() {
+            let chunk_len = rest.len().min(body_cap);
+            let (chunk, tail) = rest.split_at(chunk_len);
+            let page_id = alloc_page();
+            let mut overflow_page = Page::empty();
+            overflow_page.body_mut()[..chunk.len()].copy_from_slice(chunk);
+            // lower_offset is repurposed on overflow pages to track how many
+            // body bytes this page actually holds, since they carry no cells.
+            overflow_page.header_mut_view().lower_offset_mut().write(chunk.len() as u16);
+            chain.push((page_id, overflow_page));
+            rest = tail;
+        }
+        for i in (0..chain.len()).rev() {
+            let next = if i + 1 < chain.len() { Some(chain[i + 1].0) } else { None };
+            chain[i].1.header_mut_view().overflow_page_mut().write(MaybePageId::from_page_id(next));
+            chain[i].1.seal();
+        }
+
+        let first_overflow_page = chain.first().map(|(id, _)| *id);
+        let mut cell = Vec::with_capacity(Self::SPILL_CELL_HEADER_SIZE + local_data.len());
+        cell.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        cell.extend_from_slice(&MaybePageId::from_page_id(first_overflow_page).0.to_le_bytes());
+        cell.extend_from_slice(local_data);
+
+        self.add_cell_raw(&cell, true);
+
+        chain
+    }
+
+    fn is_cell_removed(&self, nth: usize) -> bool {
+        let at = (nth * 4) as u16;
+        self.read_pointer(at).tombstone
+    }
+
     fn read_nth_cell(&self, nth: usize) -> &[u8] {
+        if self.is_cell_removed(nth) {
+            panic!("cell removed");
+        }
+        let at = (nth * 4) as u16;
+        let ptr = self.read_pointer(at);
+        self.read_cell(ptr.addr, ptr.len)
+    }
+
+    fn cell_is_spilled(&self, nth: usize) -> bool {
         let at = (nth * 4) as u16;
-        let (ptr, len) = self.read_pointer(at);
-        self.read_cell(ptr, len)
+        self.read_pointer(at).spilled
+    }
+
+    /// Reassembles the full payload of the `nth` cell, following the overflow
+    /// chain via `fetch` if the cell was written with `add_cell_spilled`.
+    fn read_cell_full<F: FnMut(PageId) -> Page>(&self, nth: usize, mut fetch: F) -> Vec<u8> {
+        let raw = self.read_nth_cell(nth);
+        if !self.cell_is_spilled(nth) {
+            return raw.to_vec();
+        }
+
+        let total_len = u64::from_le_bytes(raw[0..8].try_into().unwrap()) as usize;
+        let first_overflow_page = u64::from_le_bytes(raw[8..16].try_into().unwrap());
+        let local_data = &raw[16..];
+
+        let mut result = Vec::with_capacity(total_len);
+        result.extend_from_slice(local_data);
+        let mut next = MaybePageId(first_overflow_page).to_page_id();
+        while let Some(page_id) = next {
+            let page = fetch(page_id);
+            let used = page.header_view().lower_offset().read() as usize;
+            result.extend_from_slice(&page.body()[..used]);
+            next = page.header_view().overflow_page().read().to_page_id();
+        }
+        result.truncate(total_len);
+        result
     }
 
     fn cells_count(&self) -> u16 {
         self.header_view().lower_offset().read() / 4
     }
+
+    /// Bit in the header `flags` field that records whether this page is a
+    /// `KeyPage` (branch, cleared) or `KeyValuePage` (leaf, set).
+    const PAGE_TYPE_BIT: u16 = 0x0001;
+
+    pub fn page_type(&self) -> PageType {
+        if self.header_view().flags().read() & Self::PAGE_TYPE_BIT != 0 {
+            PageType::KeyValuePage
+        } else {
+            PageType::KeyPage
+        }
+    }
+
+    pub fn set_page_type(&mut self, page_type: PageType) {
+        let flags = self.header_view().flags().read();
+        let flags = match page_type {
+            PageType::KeyPage => flags & !Self::PAGE_TYPE_BIT,
+            PageType::KeyValuePage => flags | Self::PAGE_TYPE_BIT,
+        };
+        self.header_mut_view().flags_mut().write(flags);
+    }
+
+    /// Decodes the leading key out of a cell written by `insert_ordered`:
+    /// `[varint key_len][key bytes][payload bytes]`.
+    fn decode_cell_key(cell: &[u8]) -> &[u8] {
+        let (key_len, prefix_len) = parse_varint(cell).expect("corrupt cell: truncated varint");
+        &cell[prefix_len..prefix_len + key_len as usize]
+    }
+
+    /// Binary searches the ordered pointer array for `key`, assuming every
+    /// non-tombstoned slot `0..cells_count()` holds an `insert_ordered` cell
+    /// sorted by key. `remove_ordered` keeps the array tombstone-free, but
+    /// this tolerates stray tombstones (e.g. left by `remove_nth_cell`)
+    /// rather than panicking on one: it compacts them out of a `live_slots`
+    /// index before searching. Mirrors `[T]::binary_search`: `Ok(slot)` on an
+    /// exact match, `Err(slot)` for the physical slot to insert before.
+    fn binary_search_key(&self, key: &[u8]) -> Result<usize, usize> {
+        let live_slots: Vec<usize> = (0..self.cells_count() as usize)
+            .filter(|&slot| !self.read_pointer((slot * 4) as u16).tombstone)
+            .collect();
+
+        let mut lo = 0usize;
+        let mut hi = live_slots.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let slot = live_slots[mid];
+            let mid_key = Self::decode_cell_key(self.read_nth_cell(slot));
+            match mid_key.cmp(key) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return Ok(slot),
+            }
+        }
+        Err(live_slots.get(lo).copied().unwrap_or(self.cells_count() as usize))
+    }
+
+    /// Slot of the cell whose key matches exactly, if any.
+    pub fn search(&self, key: &[u8]) -> Option<usize> {
+        self.binary_search_key(key).ok()
+    }
+
+    /// Makes room for one more pointer at `at_slot`, shifting every later
+    /// slot one position to the right.
+    fn insert_pointer_slot(&mut self, at_slot: usize, ptr: CellPointer) {
+        let old_count = self.cells_count() as usize;
+        let new_lower = ((old_count + 1) * 4) as u16;
+        self.header_mut_view().lower_offset_mut().write(new_lower);
+        for slot in (at_slot..old_count).rev() {
+            let moved = self.read_pointer((slot * 4) as u16);
+            self.write_pointer(((slot + 1) * 4) as u16, moved);
+        }
+        self.write_pointer((at_slot * 4) as u16, ptr);
+    }
+
+    /// Inserts (or overwrites) `key -> payload` keeping the pointer array
+    /// sorted by key, so `search` can binary-search it. For a `KeyPage` the
+    /// payload is an encoded child `PageId`; for a `KeyValuePage` it is the
+    /// value. This is the B-tree node foundation redb builds its LEAF/BRANCH
+    /// split on.
+    pub fn insert_ordered(&mut self, key: &[u8], payload: &[u8]) {
+        let search_result = self.binary_search_key(key);
+
+        let mut key_len_buf = [0u8; 9];
+        let key_len_size = write_varint(&mut key_len_buf, key.len() as u64);
+        let mut cell = Vec::with_capacity(key_len_size + key.len() + payload.len());
+        cell.extend_from_slice(&key_len_buf[..key_len_size]);
+        cell.extend_from_slice(key);
+        cell.extend_from_slice(payload);
+
+        let lower_offset = self.header_view().lower_offset().read();
+        let upper_offset = self.header_view().upper_offset().read();
+        let needed = cell.len() + if search_result.is_err() { 4 } else { 0 };
+        if needed > (upper_offset - lower_offset) as usize {
+            panic!("Overflow page");
+        }
+        let addr = upper_offset - cell.len() as u16;
+        self.write_cell_data(addr, &cell);
+        self.header_mut_view().upper_offset_mut().write(addr);
+
+        let ptr = CellPointer { addr, len: cell.len() as u16, spilled: false, tombstone: false };
+        match search_result {
+            Ok(slot) => self.write_pointer((slot * 4) as u16, ptr),
+            Err(slot) => self.insert_pointer_slot(slot, ptr),
+        }
+
+        self.update_key_summary_with(key);
+    }
+
+    /// Removes the pointer at `at_slot` outright, shifting every later slot
+    /// one position to the left. Unlike `remove_nth_cell`'s tombstone, this
+    /// shrinks `cells_count()`, so the sorted pointer array keeps no dead slots.
+    fn remove_pointer_slot(&mut self, at_slot: usize) {
+        let old_count = self.cells_count() as usize;
+        for slot in at_slot..old_count - 1 {
+            let moved = self.read_pointer(((slot + 1) * 4) as u16);
+            self.write_pointer((slot * 4) as u16, moved);
+        }
+        let new_lower = ((old_count - 1) * 4) as u16;
+        self.header_mut_view().lower_offset_mut().write(new_lower);
+    }
+
+    /// Removes the cell for `key` (if present) and keeps the page's min/max
+    /// key summary consistent with what's still live. Unlike `remove_nth_cell`,
+    /// this physically drops the pointer slot rather than tombstoning it, so
+    /// the sorted pointer array stays dense. It is only meaningful on a page
+    /// written through `insert_ordered`.
+    pub fn remove_ordered(&mut self, key: &[u8]) -> bool {
+        match self.search(key) {
+            Some(slot) => {
+                self.remove_pointer_slot(slot);
+                self.recompute_key_summary();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Truncation cap applied to keys recorded in the per-page min/max
+    /// summary; a longer key is still stored as a cell, only its footprint
+    /// in the summary region is shortened. A truncated bound stays a safe
+    /// (if looser) bound for `may_contain`/`overlaps` on the min side; pick a
+    /// cap comfortably larger than expected keys to keep it tight.
+    const SUMMARY_KEY_CAP: usize = 32;
+    const SUMMARY_SLOT_SIZE: usize = 1 + Self::SUMMARY_KEY_CAP;
+    const SUMMARY_REGION_SIZE: usize = 2 * Self::SUMMARY_SLOT_SIZE;
+    /// Sentinel stored in the max slot's tag byte (outside the `1..=SUMMARY_KEY_CAP + 1`
+    /// range that encodes a key's length plus one; 0 means no key) meaning
+    /// "no tight upper bound could be computed" — see `summary_upper_bound`. `key_range`/`may_contain`/
+    /// `overlaps` fall back to "unknown" rather than risk excluding a live key.
+    const MAX_UNBOUNDED: u8 = 0xff;
+
+    fn summary_region_base(&self) -> usize {
+        self.body().len() - Self::SUMMARY_REGION_SIZE
+    }
+
+    fn read_summary_slot(&self, which: usize) -> Option<&[u8]> {
+        let base = self.summary_region_base() + which * Self::SUMMARY_SLOT_SIZE;
+        let tag = self.body()[base];
+        if tag == 0 || tag == Self::MAX_UNBOUNDED {
+            None
+        } else {
+            let len = tag as usize - 1;
+            Some(&self.body()[base + 1..base + 1 + len])
+        }
+    }
+
+    fn write_summary_slot(&mut self, which: usize, key: Option<&[u8]>) {
+        let base = self.summary_region_base() + which * Self::SUMMARY_SLOT_SIZE;
+        match key {
+            None => self.body_mut()[base] = 0,
+            Some(k) => {
+                let len = k.len().min(Self::SUMMARY_KEY_CAP);
+                self.body_mut()[base] = len as u8 + 1;
+                self.body_mut()[base + 1..base + 1 + len].copy_from_slice(&k[..len]);
+            }
+        }
+    }
+
+    fn write_max_unbounded(&mut self) {
+        let base = self.summary_region_base() + Self::SUMMARY_SLOT_SIZE;
+        self.body_mut()[base] = Self::MAX_UNBOUNDED;
+    }
+
+    fn max_is_unbounded(&self) -> bool {
+        let base = self.summary_region_base() + Self::SUMMARY_SLOT_SIZE;
+        self.body()[base] == Self::MAX_UNBOUNDED
+    }
+
+    /// Computes a safe upper bound for `key` that fits in `SUMMARY_KEY_CAP`
+    /// bytes. A key within the cap is its own bound. A longer key would sort
+    /// *after* its own truncated prefix (a real key can only extend, never
+    /// shrink, what comes before it), so truncating alone would make
+    /// `may_contain` wrongly exclude it; instead this increments the last
+    /// non-`0xff` byte of the truncated prefix (carrying, i.e. dropping any
+    /// trailing `0xff` bytes), which is provably greater than `key` and every
+    /// other key sharing that prefix. Returns `None` only in the pathological
+    /// case where the whole truncated prefix is `0xff` bytes, which has no
+    /// representable bound tighter than "unbounded".
+    fn summary_upper_bound(key: &[u8]) -> Option<Vec<u8>> {
+        if key.len() <= Self::SUMMARY_KEY_CAP {
+            return Some(key.to_vec());
+        }
+        let mut bound = key[..Self::SUMMARY_KEY_CAP].to_vec();
+        for i in (0..bound.len()).rev() {
+            if bound[i] < 0xff {
+                bound[i] += 1;
+                bound.truncate(i + 1);
+                return Some(bound);
+            }
+        }
+        None
+    }
+
+    /// Extends the summary to cover `key`, called after every `insert_ordered`.
+    fn update_key_summary_with(&mut self, key: &[u8]) {
+        if self.read_summary_slot(0).map_or(true, |min| key < min) {
+            self.write_summary_slot(0, Some(key));
+        }
+
+        if self.max_is_unbounded() {
+            return;
+        }
+        match Self::summary_upper_bound(key) {
+            Some(bound) => {
+                if self.read_summary_slot(1).map_or(true, |max| bound.as_slice() > max) {
+                    self.write_summary_slot(1, Some(&bound));
+                }
+            }
+            None => self.write_max_unbounded(),
+        }
+    }
+
+    /// Rebuilds the summary from scratch over the still-live cells. Needed
+    /// after a removal, since shrinking the bounds (unlike growing them)
+    /// can't be done incrementally from the removed key alone.
+    fn recompute_key_summary(&mut self) {
+        let mut min: Option<Vec<u8>> = None;
+        let mut max: Option<Vec<u8>> = None;
+        let mut max_unbounded = false;
+        for slot in 0..self.cells_count() {
+            let ptr = self.read_pointer(slot * 4);
+            if ptr.tombstone {
+                continue;
+            }
+            let key = Self::decode_cell_key(self.read_cell(ptr.addr, ptr.len));
+            if min.as_deref().map_or(true, |m| key < m) {
+                min = Some(key.to_vec());
+            }
+            if !max_unbounded {
+                match Self::summary_upper_bound(key) {
+                    Some(bound) => {
+                        if max.as_deref().map_or(true, |m| bound.as_slice() > m) {
+                            max = Some(bound);
+                        }
+                    }
+                    None => max_unbounded = true,
+                }
+            }
+        }
+        self.write_summary_slot(0, min.as_deref());
+        if max_unbounded {
+            self.write_max_unbounded();
+        } else {
+            self.write_summary_slot(1, max.as_deref());
+        }
+    }
+
+    /// The keys covered by the page, if it holds any ordered cells and a
+    /// tight upper bound could be computed for all of them.
+    pub fn key_range(&self) -> Option<(&[u8], &[u8])> {
+        match (self.read_summary_slot(0), self.read_summary_slot(1)) {
+            (Some(min), Some(max)) => Some((min, max)),
+            _ => None,
+        }
+    }
+
+    /// Cheap pre-check so a scan can skip a page without decoding its cells.
+    /// Conservative: an empty/unsummarized/unbounded page always answers `true`.
+    pub fn may_contain(&self, key: &[u8]) -> bool {
+        match self.key_range() {
+            Some((min, max)) => key >= min && key <= max,
+            None => true,
+        }
+    }
+
+    /// Whether the page's key range could intersect `[lo, hi]`.
+    pub fn overlaps(&self, lo: &[u8], hi: &[u8]) -> bool {
+        match self.key_range() {
+            Some((min, max)) => min <= hi && max >= lo,
+            None => true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -167,7 +749,7 @@ mod tests {
     fn it_works() {
         let mut page = Page::empty();
         assert_eq!(page.header_view().lower_offset().read(), 0);
-        assert_eq!(page.header_view().upper_offset().read(), (PAGE_SIZE - HEADER_SIZE) as u16);
+        assert_eq!(page.header_view().upper_offset().read(), (PAGE_SIZE - HEADER_SIZE - Page::SUMMARY_REGION_SIZE) as u16);
         assert_eq!(page.header_view().overflow_page().read().to_page_id(), None);
         assert_eq!(page.header_view().flags().read(), 0);
 
@@ -182,4 +764,173 @@ mod tests {
         assert_eq!(page.read_nth_cell(3), b"Koujir");
         assert_eq!(page.cells_count(), 4);
     }
+
+    #[test]
+    fn checksum_detects_corruption() {
+        let mut page = Page::empty();
+        assert!(page.verify());
+
+        page.add_cell(b"Hello, World");
+        assert!(!page.verify(), "checksum must be resealed after mutation");
+
+        page.seal();
+        assert!(page.verify());
+
+        page.data[HEADER_SIZE] ^= 0xff;
+        assert!(!page.verify());
+    }
+
+    #[test]
+    fn spilled_cell_roundtrips_through_overflow_chain() {
+        let mut page = Page::empty();
+        let payload: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+        let mut next_id = 1u64;
+        let chain = page.add_cell_spilled(&payload, || {
+            let id = PageId(NonZeroU64::new(next_id).unwrap());
+            next_id += 1;
+            id
+        });
+
+        assert!(page.cell_is_spilled(0));
+        assert!(!chain.is_empty());
+
+        let pages: std::collections::HashMap<u64, Page> = chain
+            .into_iter()
+            .map(|(id, page)| (id.0.get(), page))
+            .collect();
+        let full = page.read_cell_full(0, |id| {
+            let found = pages.get(&id.0.get()).unwrap();
+            Page { data: found.data }
+        });
+        assert_eq!(full, payload);
+    }
+
+    #[test]
+    fn remove_reuses_freed_hole_and_compact_rebuilds_pointers() {
+        let mut page = Page::empty();
+        page.add_cell(b"Hello, World");
+        page.add_cell(b"Cop");
+        page.add_cell(b"Han Le");
+
+        page.remove_nth_cell(1);
+        assert!(page.is_cell_removed(1));
+        assert_eq!(page.free_space(), b"Cop".len());
+
+        // A cell that fits the freed hole is carved out of it instead of
+        // growing the page from upper_offset.
+        let upper_before = page.header_view().upper_offset().read();
+        page.add_cell(b"Fit");
+        assert_eq!(page.header_view().upper_offset().read(), upper_before);
+        assert_eq!(page.free_space(), 0);
+
+        page.compact();
+        assert_eq!(page.cells_count(), 3);
+        assert_eq!(page.read_nth_cell(0), b"Hello, World");
+        assert_eq!(page.read_nth_cell(1), b"Fit");
+        assert_eq!(page.read_nth_cell(2), b"Han Le");
+        assert_eq!(page.free_space(), 0);
+
+        // Compacting a page with a still-tombstoned cell drops it entirely.
+        let mut page = Page::empty();
+        page.add_cell(b"Hello, World");
+        page.add_cell(b"Cop");
+        page.add_cell(b"Han Le");
+        page.remove_nth_cell(1);
+
+        page.compact();
+        assert_eq!(page.cells_count(), 2);
+        assert_eq!(page.read_nth_cell(0), b"Hello, World");
+        assert_eq!(page.read_nth_cell(1), b"Han Le");
+        assert_eq!(page.free_space(), 0);
+    }
+
+    #[test]
+    fn ordered_insert_keeps_keys_sorted_and_searchable() {
+        let mut page = Page::empty();
+        assert_eq!(page.page_type(), PageType::KeyPage);
+        page.set_page_type(PageType::KeyValuePage);
+        assert_eq!(page.page_type(), PageType::KeyValuePage);
+
+        page.insert_ordered(b"han", b"le");
+        page.insert_ordered(b"coi", b"phuc");
+        page.insert_ordered(b"ngoc", b"anh");
+        page.insert_ordered(b"coi", b"updated");
+
+        assert_eq!(page.cells_count(), 3);
+        assert_eq!(Page::decode_cell_key(page.read_nth_cell(0)), b"coi");
+        assert_eq!(Page::decode_cell_key(page.read_nth_cell(1)), b"han");
+        assert_eq!(Page::decode_cell_key(page.read_nth_cell(2)), b"ngoc");
+
+        assert_eq!(page.search(b"coi"), Some(0));
+        assert_eq!(page.search(b"han"), Some(1));
+        assert_eq!(page.search(b"ngoc"), Some(2));
+        assert_eq!(page.search(b"missing"), None);
+
+        let coi = page.read_nth_cell(page.search(b"coi").unwrap());
+        assert_eq!(&coi[1 + 3..], b"updated"); // 1-byte varint prefix for a 3-byte key
+    }
+
+    #[test]
+    fn varint_roundtrips_edge_cases() {
+        let mut buf = [0u8; 9];
+
+        let n = write_varint(&mut buf, 0);
+        assert_eq!((n, &buf[..n]), (1, &[0u8][..]));
+        assert_eq!(parse_varint(&buf[..n]), Some((0, 1)));
+
+        let n = write_varint(&mut buf, 127);
+        assert_eq!(parse_varint(&buf[..n]), Some((127, n)));
+
+        let n = write_varint(&mut buf, 128);
+        assert_eq!(n, 2);
+        assert_eq!(parse_varint(&buf[..n]), Some((128, 2)));
+
+        let n = write_varint(&mut buf, u64::MAX);
+        assert_eq!(n, 9);
+        assert_eq!(parse_varint(&buf[..n]), Some((u64::MAX, 9)));
+
+        // truncated input must not panic
+        assert_eq!(parse_varint(&buf[..n - 1]), None);
+        assert_eq!(parse_varint(&[0x80]), None);
+    }
+
+    #[test]
+    fn key_range_tracks_inserts_and_removals() {
+        let mut page = Page::empty();
+        assert_eq!(page.key_range(), None);
+        assert!(page.may_contain(b"anything")); // no summary yet: can't rule it out
+
+        page.insert_ordered(b"han", b"le");
+        page.insert_ordered(b"coi", b"phuc");
+        page.insert_ordered(b"ngoc", b"anh");
+
+        assert_eq!(page.key_range(), Some((&b"coi"[..], &b"ngoc"[..])));
+        assert!(page.may_contain(b"han"));
+        assert!(!page.may_contain(b"aaa"));
+        assert!(!page.may_contain(b"zzz"));
+        assert!(page.overlaps(b"aaa", b"han"));
+        assert!(!page.overlaps(b"ooo", b"zzz"));
+
+        assert!(page.remove_ordered(b"coi"));
+        assert_eq!(page.key_range(), Some((&b"han"[..], &b"ngoc"[..])));
+        assert!(!page.remove_ordered(b"coi"));
+    }
+
+    #[test]
+    fn key_range_rounds_up_truncated_max_bound() {
+        let mut page = Page::empty();
+        // Longer than SUMMARY_KEY_CAP (32): its stored bound is a rounded-up
+        // prefix, not a truncation, so the key itself must still test as
+        // contained rather than sorting past the stored "max".
+        let long_key = b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaZZZZ";
+        assert!(long_key.len() > Page::SUMMARY_KEY_CAP);
+        page.insert_ordered(long_key, b"payload");
+
+        assert!(page.may_contain(long_key));
+        assert!(page.search(long_key).is_some());
+
+        let (_, max) = page.key_range().unwrap();
+        assert!(max.as_ref() > &long_key[..Page::SUMMARY_KEY_CAP]);
+    }
 }